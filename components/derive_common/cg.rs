@@ -2,13 +2,15 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use darling::{FromDeriveInput, FromField, FromVariant};
+use darling::{FromDeriveInput, FromField, FromMeta, FromVariant};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, TokenStreamExt};
+use syn::spanned::Spanned;
 use syn::{
-    self, parse_quote, AngleBracketedGenericArguments, Binding, DeriveInput, Field,
-    GenericArgument, GenericParam, Ident, Path, PathArguments, PathSegment, QSelf, Type, TypeArray,
-    TypeGroup, TypeParam, TypeParen, TypePath, TypeSlice, TypeTuple, Variant, WherePredicate,
+    self, parse_quote, AngleBracketedGenericArguments, BareFnArg, Binding, DeriveInput, Field,
+    GenericArgument, GenericParam, Ident, Path, PathArguments, PathSegment, QSelf, ReturnType, Type,
+    TypeArray, TypeBareFn, TypeGroup, TypeImplTrait, TypeParam, TypeParamBound, TypeParen, TypePath,
+    TypePtr, TypeReference, TypeSlice, TypeTraitObject, TypeTuple, Variant, WherePredicate,
 };
 use synstructure::{self, BindStyle, BindingInfo, VariantAst, VariantInfo};
 
@@ -31,37 +33,65 @@ use synstructure::{self, BindStyle, BindingInfo, VariantAst, VariantInfo};
 /// ```
 ///
 /// This needs to run before adding other bounds to the type parameters.
+///
+/// Returns `Err` instead of panicking on a where-clause shape this function
+/// doesn't understand; callers that used to treat this as infallible (every
+/// derive crate built on `derive_common` before this change did) need to
+/// propagate the error with `?` from their own `syn::Result`-returning
+/// entry point.
 pub fn propagate_clauses_to_output_type(
     where_clause: &mut Option<syn::WhereClause>,
     generics: &syn::Generics,
     trait_path: &Path,
     trait_output: &Ident,
-) {
+) -> syn::Result<()> {
     let where_clause = match *where_clause {
         Some(ref mut clause) => clause,
-        None => return,
+        None => return Ok(()),
     };
     let mut extra_bounds = vec![];
+    let mut errors: Option<syn::Error> = None;
+
     for pred in &where_clause.predicates {
         let ty = match *pred {
             syn::WherePredicate::Type(ref ty) => ty,
-            ref predicate => panic!("Unhanded complex where predicate: {:?}", predicate),
+            ref predicate => {
+                combine_error(
+                    &mut errors,
+                    syn::Error::new_spanned(predicate, "unhandled complex where predicate"),
+                );
+                continue;
+            },
         };
 
         let path = match ty.bounded_ty {
             syn::Type::Path(ref p) => &p.path,
-            ref ty => panic!("Unhanded complex where type: {:?}", ty),
+            ref ty => {
+                combine_error(
+                    &mut errors,
+                    syn::Error::new(ty.span(), "unhandled complex where type"),
+                );
+                continue;
+            },
         };
 
-        assert!(
-            ty.lifetimes.is_none(),
-            "Unhanded complex lifetime bound: {:?}",
-            ty,
-        );
+        if let Some(ref lifetimes) = ty.lifetimes {
+            combine_error(
+                &mut errors,
+                syn::Error::new_spanned(lifetimes, "unhandled complex lifetime bound"),
+            );
+            continue;
+        }
 
         let ident = match path_to_ident(path) {
             Some(i) => i,
-            None => panic!("Unhanded complex where type path: {:?}", path),
+            None => {
+                combine_error(
+                    &mut errors,
+                    syn::Error::new_spanned(path, "unhandled complex where type path"),
+                );
+                continue;
+            },
         };
 
         if generics.type_params().any(|param| param.ident == *ident) {
@@ -69,6 +99,10 @@ pub fn propagate_clauses_to_output_type(
         }
     }
 
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
     for bound in extra_bounds {
         let ty = bound.bounded_ty;
         let bounds = bound.bounds;
@@ -76,6 +110,15 @@ pub fn propagate_clauses_to_output_type(
             .predicates
             .push(parse_quote!(<#ty as #trait_path>::#trait_output: #bounds))
     }
+
+    Ok(())
+}
+
+fn combine_error(errors: &mut Option<syn::Error>, error: syn::Error) {
+    match errors {
+        Some(errors) => errors.combine(error),
+        None => *errors = Some(error),
+    }
 }
 
 pub fn add_predicate(where_clause: &mut Option<syn::WhereClause>, pred: WherePredicate) {
@@ -157,19 +200,28 @@ pub fn fmap_trait_output(input: &DeriveInput, trait_path: &Path, trait_output: &
     segment.into()
 }
 
-pub fn map_type_params<F>(ty: &Type, params: &[&TypeParam], self_type: &Path, f: &mut F) -> Type
+/// Returns `Err` instead of panicking on a type shape it can't map yet;
+/// like the other helpers in this file that switched from panicking to
+/// `syn::Result`, every caller needs updating to propagate the error
+/// instead of assuming this always returns a `Type` outright.
+pub fn map_type_params<F>(
+    ty: &Type,
+    params: &[&TypeParam],
+    self_type: &Path,
+    f: &mut F,
+) -> syn::Result<Type>
 where
     F: FnMut(&Ident) -> Type,
 {
-    match *ty {
+    Ok(match *ty {
         Type::Slice(ref inner) => Type::from(TypeSlice {
-            elem: Box::new(map_type_params(&inner.elem, params, self_type, f)),
+            elem: Box::new(map_type_params(&inner.elem, params, self_type, f)?),
             ..inner.clone()
         }),
         Type::Array(ref inner) => {
             //ref ty, ref expr) => {
             Type::from(TypeArray {
-                elem: Box::new(map_type_params(&inner.elem, params, self_type, f)),
+                elem: Box::new(map_type_params(&inner.elem, params, self_type, f)?),
                 ..inner.clone()
             })
         },
@@ -179,7 +231,7 @@ where
                 .elems
                 .iter()
                 .map(|ty| map_type_params(&ty, params, self_type, f))
-                .collect(),
+                .collect::<syn::Result<_>>()?,
             ..inner.clone()
         }),
         Type::Path(TypePath {
@@ -188,41 +240,122 @@ where
         }) => {
             if let Some(ident) = path_to_ident(path) {
                 if params.iter().any(|ref param| &param.ident == ident) {
-                    return f(ident);
+                    return Ok(f(ident));
                 }
                 if ident == "Self" {
-                    return Type::from(TypePath {
+                    return Ok(Type::from(TypePath {
                         qself: None,
                         path: self_type.clone(),
-                    });
+                    }));
                 }
             }
             Type::from(TypePath {
                 qself: None,
-                path: map_type_params_in_path(path, params, self_type, f),
+                path: map_type_params_in_path(path, params, self_type, f)?,
             })
         },
         Type::Path(TypePath {
             ref qself,
             ref path,
         }) => Type::from(TypePath {
-            qself: qself.as_ref().map(|qself| QSelf {
-                ty: Box::new(map_type_params(&qself.ty, params, self_type, f)),
-                position: qself.position,
-                ..qself.clone()
-            }),
-            path: map_type_params_in_path(path, params, self_type, f),
+            qself: qself
+                .as_ref()
+                .map(|qself| -> syn::Result<_> {
+                    Ok(QSelf {
+                        ty: Box::new(map_type_params(&qself.ty, params, self_type, f)?),
+                        position: qself.position,
+                        ..qself.clone()
+                    })
+                })
+                .transpose()?,
+            path: map_type_params_in_path(path, params, self_type, f)?,
         }),
         Type::Paren(ref inner) => Type::from(TypeParen {
-            elem: Box::new(map_type_params(&inner.elem, params, self_type, f)),
+            elem: Box::new(map_type_params(&inner.elem, params, self_type, f)?),
             ..inner.clone()
         }),
         Type::Group(ref inner) => Type::from(TypeGroup {
-            elem: Box::new(map_type_params(&inner.elem, params, self_type, f)),
+            elem: Box::new(map_type_params(&inner.elem, params, self_type, f)?),
             ..inner.clone()
         }),
-        ref ty => panic!("type {:?} cannot be mapped yet", ty),
-    }
+        Type::Reference(ref inner) => Type::from(TypeReference {
+            elem: Box::new(map_type_params(&inner.elem, params, self_type, f)?),
+            ..inner.clone()
+        }),
+        Type::Ptr(ref inner) => Type::from(TypePtr {
+            elem: Box::new(map_type_params(&inner.elem, params, self_type, f)?),
+            ..inner.clone()
+        }),
+        Type::BareFn(ref inner) => Type::from(TypeBareFn {
+            inputs: inner
+                .inputs
+                .iter()
+                .map(|arg| -> syn::Result<_> {
+                    Ok(BareFnArg {
+                        ty: map_type_params(&arg.ty, params, self_type, f)?,
+                        ..arg.clone()
+                    })
+                })
+                .collect::<syn::Result<_>>()?,
+            output: match inner.output {
+                ReturnType::Default => ReturnType::Default,
+                ReturnType::Type(ref arrow, ref ty) => ReturnType::Type(
+                    *arrow,
+                    Box::new(map_type_params(ty, params, self_type, f)?),
+                ),
+            },
+            ..inner.clone()
+        }),
+        Type::TraitObject(ref inner) => Type::from(TypeTraitObject {
+            bounds: inner
+                .bounds
+                .iter()
+                .map(|bound| -> syn::Result<_> {
+                    Ok(match *bound {
+                        TypeParamBound::Trait(ref trait_bound) => {
+                            TypeParamBound::Trait(syn::TraitBound {
+                                path: map_type_params_in_path(
+                                    &trait_bound.path,
+                                    params,
+                                    self_type,
+                                    f,
+                                )?,
+                                ..trait_bound.clone()
+                            })
+                        },
+                        ref bound @ TypeParamBound::Lifetime(_) => bound.clone(),
+                    })
+                })
+                .collect::<syn::Result<_>>()?,
+            ..inner.clone()
+        }),
+        Type::ImplTrait(ref inner) => Type::from(TypeImplTrait {
+            bounds: inner
+                .bounds
+                .iter()
+                .map(|bound| -> syn::Result<_> {
+                    Ok(match *bound {
+                        TypeParamBound::Trait(ref trait_bound) => {
+                            TypeParamBound::Trait(syn::TraitBound {
+                                path: map_type_params_in_path(
+                                    &trait_bound.path,
+                                    params,
+                                    self_type,
+                                    f,
+                                )?,
+                                ..trait_bound.clone()
+                            })
+                        },
+                        ref bound @ TypeParamBound::Lifetime(_) => bound.clone(),
+                    })
+                })
+                .collect::<syn::Result<_>>()?,
+            ..inner.clone()
+        }),
+        ref ty => {
+            return Err(syn::Error::new(ty.span(), "type cannot be mapped yet"));
+        },
+    })
 }
 
 fn map_type_params_in_path<F>(
@@ -230,46 +363,217 @@ fn map_type_params_in_path<F>(
     params: &[&TypeParam],
     self_type: &Path,
     f: &mut F,
-) -> Path
+) -> syn::Result<Path>
 where
     F: FnMut(&Ident) -> Type,
 {
-    Path {
+    Ok(Path {
         leading_colon: path.leading_colon,
         segments: path
             .segments
             .iter()
-            .map(|segment| PathSegment {
-                ident: segment.ident.clone(),
-                arguments: match segment.arguments {
-                    PathArguments::AngleBracketed(ref data) => {
-                        PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                            args: data
-                                .args
-                                .iter()
-                                .map(|arg| match arg {
-                                    ty @ &GenericArgument::Lifetime(_) => ty.clone(),
-                                    &GenericArgument::Type(ref data) => GenericArgument::Type(
-                                        map_type_params(data, params, self_type, f),
-                                    ),
-                                    &GenericArgument::Binding(ref data) => {
-                                        GenericArgument::Binding(Binding {
-                                            ty: map_type_params(&data.ty, params, self_type, f),
-                                            ..data.clone()
+            .map(|segment| -> syn::Result<_> {
+                Ok(PathSegment {
+                    ident: segment.ident.clone(),
+                    arguments: match segment.arguments {
+                        PathArguments::AngleBracketed(ref data) => {
+                            PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                                args: data
+                                    .args
+                                    .iter()
+                                    .map(|arg| -> syn::Result<_> {
+                                        Ok(match arg {
+                                            ty @ &GenericArgument::Lifetime(_) => ty.clone(),
+                                            &GenericArgument::Type(ref data) => {
+                                                GenericArgument::Type(map_type_params(
+                                                    data, params, self_type, f,
+                                                )?)
+                                            },
+                                            &GenericArgument::Binding(ref data) => {
+                                                GenericArgument::Binding(Binding {
+                                                    ty: map_type_params(
+                                                        &data.ty, params, self_type, f,
+                                                    )?,
+                                                    ..data.clone()
+                                                })
+                                            },
+                                            ref arg => {
+                                                return Err(syn::Error::new(
+                                                    arg.span(),
+                                                    "argument cannot be mapped yet",
+                                                ));
+                                            },
                                         })
-                                    },
-                                    ref arg => panic!("arguments {:?} cannot be mapped yet", arg),
-                                })
-                                .collect(),
-                            ..data.clone()
-                        })
+                                    })
+                                    .collect::<syn::Result<_>>()?,
+                                ..data.clone()
+                            })
+                        },
+                        ref arg @ PathArguments::None => arg.clone(),
+                        ref parameters => {
+                            return Err(syn::Error::new(
+                                parameters.span(),
+                                "parameters cannot be mapped yet",
+                            ));
+                        },
                     },
-                    ref arg @ PathArguments::None => arg.clone(),
-                    ref parameters => panic!("parameters {:?} cannot be mapped yet", parameters),
-                },
+                })
             })
-            .collect(),
+            .collect::<syn::Result<_>>()?,
+    })
+}
+
+/// Per-field attributes understood by [`derive_visit_and_fold`], e.g.
+/// `#[visit(skip)]` to prune a field (and everything reachable through it)
+/// out of the generated traversal.
+#[derive(Default, FromField)]
+#[darling(attributes(visit), default)]
+pub struct VisitFieldAttrs {
+    #[darling(default)]
+    pub skip: bool,
+}
+
+/// Implemented by the visitor passed to a generated `visit` method (see
+/// [`derive_visit_and_fold`]). `visit` is generic so a single visitor can be
+/// handed fields of different concrete types as the traversal descends.
+pub trait Visitor {
+    fn visit<T>(&mut self, item: &T);
+}
+
+/// Implemented by the folder passed to a generated `fold` method (see
+/// [`derive_visit_and_fold`]). Like [`Visitor::visit`], `fold` is generic so
+/// one folder can rebuild fields of different concrete types.
+pub trait Folder {
+    fn fold<T>(&mut self, item: T) -> T;
+}
+
+/// Generates a `visit`/`fold` pair of methods that walk every field of
+/// `input` which is (or contains) one of its type parameters, analogous to
+/// how `fmap_match` walks every field to build a single mapped value.
+///
+/// A field takes part when `map_type_params` finds one of the type
+/// parameters anywhere in its type, including inside containers
+/// (`Vec<T>`, `Option<T>`, slices, tuples). Such a field is handed to the
+/// visitor/folder as a whole; the elements of a container field are not
+/// visited or folded one by one.
+pub fn derive_visit_and_fold(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let params = input
+        .generics
+        .type_params()
+        .collect::<Vec<_>>();
+
+    let mut visit_body = TokenStream::new();
+    let mut fold_body = TokenStream::new();
+    let mut errors: Option<syn::Error> = None;
+
+    // `synstructure`'s `each` (for `visit`, which only calls into the
+    // visitor and builds no value) and `fmap_match` (for `fold`, which
+    // rebuilds each variant) walk every binding in every variant for us; we
+    // only need to decide, per binding, whether it mentions one of the
+    // type parameters we care about (after honoring `#[visit(skip)]`). A
+    // malformed `#[visit(...)]` attribute or an unmappable field type is
+    // collected into `errors` rather than silently treated as "don't skip"
+    // / "doesn't mention the param" - the field just contributes nothing to
+    // this iteration's body, and the accumulated error is returned once
+    // both matches have run.
+    let mut visit_structure = synstructure::Structure::new(input);
+    visit_structure.bind_with(|_| BindStyle::Ref);
+    let visit_match = visit_structure.each(|binding| {
+        if should_skip_field(binding, &mut errors) {
+            return quote!();
+        }
+        let mentions_param = mentions_any_type_param(&binding.ast().ty, &params)
+            .unwrap_or_else(|e| {
+                combine_error(&mut errors, e);
+                false
+            });
+        if !mentions_param {
+            return quote!();
+        }
+        let field = &binding.binding;
+        quote! { visitor.visit(#field); }
+    });
+    visit_body.append_all(visit_match);
+
+    let fold_match = fmap_match(input, BindStyle::Move, |binding| {
+        let field = &binding.binding;
+        if should_skip_field(binding, &mut errors) {
+            return quote! { #field };
+        }
+        let mentions_param = mentions_any_type_param(&binding.ast().ty, &params)
+            .unwrap_or_else(|e| {
+                combine_error(&mut errors, e);
+                false
+            });
+        if mentions_param {
+            quote! { folder.fold(#field) }
+        } else {
+            quote! { #field }
+        }
+    });
+    fold_body.append_all(fold_match);
+
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    let where_clause = input.generics.where_clause.clone();
+    let name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Visits every descendant of `self` that is reachable through a
+            /// field mentioning the traversed type parameter(s).
+            pub fn visit<V: Visitor>(&self, visitor: &mut V) {
+                match *self {
+                    #visit_body
+                }
+            }
+
+            /// Like `visit`, but rebuilds `self` from the folded value of
+            /// each visited descendant.
+            pub fn fold<F: Folder>(self, folder: &mut F) -> Self {
+                match self {
+                    #fold_body
+                }
+            }
+        }
+    })
+}
+
+/// Whether `binding` should be pruned from the generated traversal, i.e. it
+/// carries a well-formed `#[visit(skip)]`. A malformed `#[visit(...)]`
+/// attribute is recorded in `errors` and treated as "don't skip" for this
+/// binding only - the caller bails out via the accumulated error before any
+/// of these partial decisions reach the generated code.
+fn should_skip_field(binding: &BindingInfo, errors: &mut Option<syn::Error>) -> bool {
+    if !binding
+        .ast()
+        .attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("visit"))
+    {
+        return false;
     }
+    match parse_field_attrs::<VisitFieldAttrs>(binding.ast()) {
+        Ok(attrs) => attrs.skip,
+        Err(e) => {
+            combine_error(errors, e);
+            false
+        },
+    }
+}
+
+/// Whether `ty` mentions any of `params` anywhere within it (including
+/// inside containers like `Vec<T>`/`Option<T>`), used to decide whether a
+/// field needs to participate in a generated `visit`/`fold` traversal.
+fn mentions_any_type_param(ty: &Type, params: &[&TypeParam]) -> syn::Result<bool> {
+    let mut found = false;
+    map_type_params(ty, params, &parse_quote!(Self), &mut |_ident| {
+        found = true;
+        parse_quote!(())
+    })?;
+    Ok(found)
 }
 
 fn path_to_ident(path: &Path) -> Option<&Ident> {
@@ -288,27 +592,32 @@ fn path_to_ident(path: &Path) -> Option<&Ident> {
     }
 }
 
-pub fn parse_field_attrs<A>(field: &Field) -> A
+/// Returns `Err` (rather than panicking on a malformed `darling` attribute)
+/// so a bad `#[foo(...)]` on user code becomes a normal compile error
+/// pointing at the field, instead of aborting the whole macro expansion.
+/// Every one of this crate's callers (the `ToCss`/`ToComputedValue`/
+/// `ToAnimatedValue`/etc. derives) needs to propagate this with `?` from
+/// their own `syn::Result`-returning entry point rather than unwrapping it.
+pub fn parse_field_attrs<A>(field: &Field) -> syn::Result<A>
 where
     A: FromField,
 {
-    match A::from_field(field) {
-        Ok(attrs) => attrs,
-        Err(e) => panic!("failed to parse field attributes: {}", e),
-    }
+    A::from_field(field)
+        .map_err(|e| syn::Error::new_spanned(field, format!("failed to parse field attributes: {}", e)))
 }
 
-pub fn parse_input_attrs<A>(input: &DeriveInput) -> A
+/// Same `syn::Result` contract as [`parse_field_attrs`]; see its doc comment
+/// for what callers need to change.
+pub fn parse_input_attrs<A>(input: &DeriveInput) -> syn::Result<A>
 where
     A: FromDeriveInput,
 {
-    match A::from_derive_input(input) {
-        Ok(attrs) => attrs,
-        Err(e) => panic!("failed to parse input attributes: {}", e),
-    }
+    A::from_derive_input(input).map_err(|e| {
+        syn::Error::new_spanned(&input.ident, format!("failed to parse input attributes: {}", e))
+    })
 }
 
-pub fn parse_variant_attrs_from_ast<A>(variant: &VariantAst) -> A
+pub fn parse_variant_attrs_from_ast<A>(variant: &VariantAst) -> syn::Result<A>
 where
     A: FromVariant,
 {
@@ -321,14 +630,18 @@ where
     parse_variant_attrs(&v)
 }
 
-pub fn parse_variant_attrs<A>(variant: &Variant) -> A
+/// Same `syn::Result` contract as [`parse_field_attrs`]; see its doc comment
+/// for what callers need to change.
+pub fn parse_variant_attrs<A>(variant: &Variant) -> syn::Result<A>
 where
     A: FromVariant,
 {
-    match A::from_variant(variant) {
-        Ok(attrs) => attrs,
-        Err(e) => panic!("failed to parse variant attributes: {}", e),
-    }
+    A::from_variant(variant).map_err(|e| {
+        syn::Error::new_spanned(
+            &variant.ident,
+            format!("failed to parse variant attributes: {}", e),
+        )
+    })
 }
 
 pub fn ref_pattern<'a>(
@@ -352,10 +665,102 @@ pub fn value<'a>(variant: &'a VariantInfo, prefix: &str) -> (TokenStream, Vec<Bi
     (v.pat(), v.bindings().to_vec())
 }
 
+/// The casing strategy used to turn a Rust identifier (`FooBar`, a variant
+/// or field name) into the string serialized for CSS, selectable per
+/// container with `#[css(rename_all = "...")]` or per variant/field with
+/// `#[css(rename = "...")]`.
+///
+/// Parsed via darling's `FromMeta` so it can be used directly as the type
+/// of a field on whatever attrs struct a derive crate passes through
+/// `parse_input_attrs`/`parse_variant_attrs`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RenameRule {
+    /// `FooBar` -> `foo-bar` (the historical, and still default, behavior).
+    KebabCase,
+    /// `FooBar` -> `foo_bar`.
+    SnakeCase,
+    /// `FooBar` -> `FOO_BAR`.
+    ScreamingSnakeCase,
+    /// `FooBar` -> `foobar`.
+    LowerCase,
+    /// Left untouched.
+    Verbatim,
+}
+
+impl Default for RenameRule {
+    fn default() -> Self {
+        RenameRule::KebabCase
+    }
+}
+
+impl FromMeta for RenameRule {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Ok(match value {
+            "kebab-case" => RenameRule::KebabCase,
+            "snake_case" => RenameRule::SnakeCase,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnakeCase,
+            "lowercase" => RenameRule::LowerCase,
+            "verbatim" => RenameRule::Verbatim,
+            other => {
+                return Err(darling::Error::unknown_value(other));
+            },
+        })
+    }
+}
+
+impl RenameRule {
+    /// Applies this rule to a Rust identifier, the way `to_css_identifier`
+    /// used to unconditionally assume kebab-case.
+    pub fn apply(&self, camel_case: &str) -> String {
+        match *self {
+            RenameRule::KebabCase => to_css_identifier(camel_case),
+            RenameRule::SnakeCase => to_css_identifier(camel_case).replace('-', "_"),
+            RenameRule::ScreamingSnakeCase => to_scream_case(&to_css_identifier(camel_case)),
+            RenameRule::LowerCase => camel_case.to_lowercase(),
+            RenameRule::Verbatim => camel_case.to_string(),
+        }
+    }
+}
+
+/// Per-container `#[css(rename_all = "...")]`, parsed via
+/// `parse_input_attrs`. A derive crate's own attrs struct embeds this with
+/// `#[darling(flatten)]` so it gets the casing-strategy field for free.
+#[derive(Default, FromDeriveInput)]
+#[darling(attributes(css), default)]
+pub struct CssInputAttrs {
+    #[darling(default)]
+    pub rename_all: RenameRule,
+}
+
+/// Per-variant/per-field `#[css(rename = "...")]` override, parsed via
+/// `parse_variant_attrs`/`parse_field_attrs`. Takes precedence over the
+/// container's `rename_all` for that one identifier.
+#[derive(Default, FromField, FromVariant)]
+#[darling(attributes(css), default)]
+pub struct CssRenameAttrs {
+    #[darling(default)]
+    pub rename: Option<String>,
+}
+
+/// Routes a single identifier through the selected casing strategy: an
+/// explicit per-variant/per-field `#[css(rename = "...")]` wins outright,
+/// otherwise the container's `#[css(rename_all = "...")]` (defaulting to
+/// `RenameRule::KebabCase`, i.e. plain `to_css_identifier`) applies.
+pub fn css_identifier(camel_case: &str, container: &CssInputAttrs, member: &CssRenameAttrs) -> String {
+    match &member.rename {
+        Some(rename) => rename.clone(),
+        None => container.rename_all.apply(camel_case),
+    }
+}
+
 /// Transforms "FooBar" to "foo-bar".
 ///
 /// If the first Camel segment is "Moz", "Webkit", or "Servo", the result string
 /// is prepended with "-".
+///
+/// This is the `RenameRule::KebabCase` behavior, and remains the default
+/// a derive crate falls back to when no `#[css(rename_all = "...")]` (or
+/// per-variant `#[css(rename = "...")]`) attribute is present.
 pub fn to_css_identifier(mut camel_case: &str) -> String {
     camel_case = camel_case.trim_end_matches('_');
     let mut first = true;
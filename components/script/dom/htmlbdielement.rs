@@ -0,0 +1,47 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use dom_struct::dom_struct;
+use html5ever::{LocalName, Prefix};
+use js::rust::HandleObject;
+
+use crate::dom::bindings::root::DomRoot;
+use crate::dom::document::Document;
+use crate::dom::htmlelement::HTMLElement;
+use crate::dom::node::Node;
+
+// https://html.spec.whatwg.org/multipage/#the-bdi-element
+//
+// A `bdi` element with no valid `dir` attribute behaves as if `dir=auto` were
+// specified; see `HTMLElement::directionality`.
+#[dom_struct]
+pub struct HTMLBDIElement {
+    htmlelement: HTMLElement,
+}
+
+impl HTMLBDIElement {
+    fn new_inherited(
+        local_name: LocalName,
+        prefix: Option<Prefix>,
+        document: &Document,
+    ) -> HTMLBDIElement {
+        HTMLBDIElement {
+            htmlelement: HTMLElement::new_inherited(local_name, prefix, document),
+        }
+    }
+
+    #[allow(unrooted_must_root)]
+    pub fn new(
+        local_name: LocalName,
+        prefix: Option<Prefix>,
+        document: &Document,
+        proto: Option<HandleObject>,
+    ) -> DomRoot<HTMLBDIElement> {
+        Node::reflect_node_with_proto(
+            Box::new(HTMLBDIElement::new_inherited(local_name, prefix, document)),
+            document,
+            proto,
+        )
+    }
+}